@@ -0,0 +1,142 @@
+use std::collections::{HashMap, HashSet};
+
+use indexmap::IndexMap;
+use log::warn;
+
+use crate::error::AppResult;
+use crate::fetch::FetchClient;
+use crate::github::EmojiLiteral;
+use crate::unicode_list::{to_title_case, FullEmojiList, FullEmojiListEntry};
+use crate::Emoji;
+
+pub type Categorized = IndexMap<String, IndexMap<String, Vec<Vec<String>>>>;
+
+pub async fn categorize_github_emoji_ids(
+    fetch_client: &FetchClient,
+    github_emoji_id_map: HashMap<String, EmojiLiteral>,
+) -> AppResult<(Categorized, Vec<Emoji>)> {
+    let url = "https://unicode.org/emoji/charts/full-emoji-list.txt";
+    let list_text = fetch_client
+        .get_text(url, reqwest::header::HeaderMap::new())
+        .await?;
+
+    let mut github_specific_emoji_uri_to_github_emoji_ids_map = HashMap::new();
+    let mut emoji_literal_to_github_emoji_ids_map: HashMap<String, Vec<String>> = HashMap::new();
+    for (emoji_id, emoji_literal) in github_emoji_id_map {
+        match emoji_literal {
+            EmojiLiteral::Unicode(emoji_code_points) => {
+                let emoji_literal_str: String = emoji_code_points.into_iter().collect();
+                emoji_literal_to_github_emoji_ids_map
+                    .entry(emoji_literal_str)
+                    .or_default()
+                    .push(emoji_id);
+            }
+            EmojiLiteral::Custom { asset_name, url } => {
+                github_specific_emoji_uri_to_github_emoji_ids_map
+                    .entry(asset_name)
+                    .or_insert_with(|| (url, Vec::new()))
+                    .1
+                    .push(emoji_id);
+            }
+        }
+    }
+    // `github_emoji_id_map` came in as a `HashMap`, so both of the above
+    // groupings are in an iteration order that varies between runs. Sort
+    // each alias group so the chosen primary shortcode (`[0]`) is stable
+    // instead (the custom-emoji section is ordered by asset name below).
+    for ids in emoji_literal_to_github_emoji_ids_map.values_mut() {
+        ids.sort();
+    }
+    for (_, ids) in github_specific_emoji_uri_to_github_emoji_ids_map.values_mut() {
+        ids.sort();
+    }
+
+    let mut categorized_emoji_ids: Categorized = IndexMap::new();
+    let mut emojis = Vec::new();
+    let mut category = String::new();
+    let mut subcategory = String::new();
+    let mut matched_literals = HashSet::new();
+
+    for entry in FullEmojiList::new(&list_text) {
+        match entry {
+            FullEmojiListEntry::Category(title) => {
+                let title = to_title_case(title);
+                category = title.clone();
+                subcategory = String::new();
+                categorized_emoji_ids.entry(title).or_default();
+            }
+            FullEmojiListEntry::Subcategory(title) => {
+                let title = to_title_case(title);
+                subcategory = title.clone();
+                categorized_emoji_ids
+                    .entry(category.clone())
+                    .or_default()
+                    .entry(title)
+                    .or_default();
+            }
+            FullEmojiListEntry::Emoji(key) => {
+                if let Some(github_emoji_ids) = emoji_literal_to_github_emoji_ids_map.get(&key) {
+                    if category.is_empty() || subcategory.is_empty() {
+                        continue;
+                    }
+                    matched_literals.insert(key.clone());
+                    let github_emoji_ids = github_emoji_ids.clone();
+                    emojis.push(Emoji {
+                        shortcode: github_emoji_ids[0].clone(),
+                        aliases: github_emoji_ids[1..].to_vec(),
+                        unicode: Some(key),
+                        category: category.clone(),
+                        subcategory: subcategory.clone(),
+                        asset_url: None,
+                    });
+                    categorized_emoji_ids
+                        .entry(category.clone())
+                        .or_default()
+                        .entry(subcategory.clone())
+                        .or_default()
+                        .push(github_emoji_ids);
+                }
+            }
+        }
+    }
+
+    for (literal, github_emoji_ids) in &emoji_literal_to_github_emoji_ids_map {
+        if !matched_literals.contains(literal) {
+            warn!(
+                "github emoji(s) {:?} have no match in full-emoji-list.txt for literal `{literal}`",
+                github_emoji_ids
+            );
+        }
+    }
+
+    if !github_specific_emoji_uri_to_github_emoji_ids_map.is_empty() {
+        let mut custom_asset_names: Vec<&String> =
+            github_specific_emoji_uri_to_github_emoji_ids_map
+                .keys()
+                .collect();
+        custom_asset_names.sort();
+
+        let custom_emojis: Vec<Vec<String>> = custom_asset_names
+            .into_iter()
+            .map(|asset_name| {
+                let (asset_url, github_emoji_ids) =
+                    &github_specific_emoji_uri_to_github_emoji_ids_map[asset_name];
+                emojis.push(Emoji {
+                    shortcode: github_emoji_ids[0].clone(),
+                    aliases: github_emoji_ids[1..].to_vec(),
+                    unicode: None,
+                    category: "GitHub Custom Emoji".to_string(),
+                    subcategory: String::new(),
+                    asset_url: Some(asset_url.clone()),
+                });
+                github_emoji_ids.clone()
+            })
+            .collect();
+        categorized_emoji_ids.insert(
+            "GitHub Custom Emoji".to_string(),
+            [("".to_string(), custom_emojis)].iter().cloned().collect(),
+        );
+    }
+
+    Ok((categorized_emoji_ids, emojis))
+}
@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+/// Generate an emoji cheat sheet from the GitHub emoji API and the Unicode
+/// full emoji list.
+#[derive(Debug, Parser)]
+#[command(name = "emoji-cheat-sheet-rs", version, about)]
+pub struct Cli {
+    /// Number of emoji columns per table row.
+    #[arg(long, default_value_t = 2)]
+    pub columns: usize,
+
+    /// Path to write the generated cheat sheet to.
+    #[arg(long, default_value = "readme.md")]
+    pub output: PathBuf,
+
+    /// Title used for the table of contents section.
+    #[arg(long, default_value = "Table of Contents")]
+    pub toc_title: String,
+
+    /// Replay GitHub/Unicode responses from this cache directory instead of
+    /// hitting the network; a response missing from it is an error. Without
+    /// this flag, responses are recorded to and replayed from a default
+    /// cache directory.
+    #[arg(long, value_name = "DIR")]
+    pub offline: Option<PathBuf>,
+
+    /// Output format for the generated cheat sheet.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Markdown)]
+    pub format: OutputFormat,
+
+    /// Add a name column beside each shortcode in the markdown output, filled
+    /// from a small hand-curated glossary of common reaction names for this
+    /// locale (`de`, `fr`, `es`, `ja`). This is not full localization — only
+    /// a short list of common emoji are covered per locale (built in via its
+    /// `locale-<code>` Cargo feature), and anything uncovered shows the
+    /// English shortcode in that column. The JSON, HTML and emoji pack
+    /// formats ignore this option.
+    #[arg(long)]
+    pub locale: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    Markdown,
+    Json,
+    Html,
+    /// A Misskey/Firefish-importable custom emoji pack (`meta.json` + PNGs,
+    /// zipped). `--output` is the path to the resulting `.zip`.
+    EmojiPack,
+}
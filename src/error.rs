@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+/// Failure modes for the cheat sheet generator, surfaced to the CLI with
+/// `error_stack::Report` attachments (failing URL, offending emoji id, ...)
+/// instead of a bare `Box<dyn Error>`.
+#[derive(Debug, Error)]
+pub enum AppError {
+    #[error("request to {0} failed")]
+    Fetch(String),
+
+    #[error("could not parse response from {0} as JSON")]
+    Json(String),
+
+    #[error("emoji id `{0}` has no recognizable Unicode code points")]
+    InvalidCodePoint(String),
+
+    #[error("could not read Cargo.toml")]
+    Manifest,
+
+    #[error("could not render the cheat sheet as {0}")]
+    Render(String),
+
+    #[error("could not write output file `{0}`")]
+    Output(String),
+}
+
+pub type AppResult<T> = error_stack::Result<T, AppError>;
@@ -0,0 +1,128 @@
+use std::path::PathBuf;
+
+use error_stack::ResultExt;
+use log::debug;
+use reqwest::header::HeaderMap;
+
+use crate::error::{AppError, AppResult};
+
+/// Record/replay wrapper around `reqwest`, shared by both the GitHub and
+/// Unicode fetches, so the generator can be rebuilt hermetically instead of
+/// hitting the network on every run.
+///
+/// On first use of a URL the response body is persisted under `cache_dir`
+/// keyed by the URL. On later runs (or whenever `offline` is set) the cached
+/// body is replayed instead of going over the network; with `offline` set, a
+/// cache miss is an error rather than a fetch.
+pub struct FetchClient {
+    client: reqwest::Client,
+    cache_dir: PathBuf,
+    offline: bool,
+}
+
+impl FetchClient {
+    pub fn new(cache_dir: PathBuf, offline: bool) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            cache_dir,
+            offline,
+        }
+    }
+
+    pub async fn get_text(&self, url: &str, headers: HeaderMap) -> AppResult<String> {
+        let cache_path = self.cache_dir.join(cache_key(url));
+
+        if cache_path.exists() {
+            let cached = std::fs::read_to_string(&cache_path)
+                .change_context_lazy(|| AppError::Fetch(url.to_string()))?;
+            debug!(
+                "replaying cached response for {url} from {}",
+                cache_path.display()
+            );
+            return Ok(cached);
+        }
+
+        if self.offline {
+            return Err(AppError::Fetch(url.to_string())).attach_printable_lazy(|| {
+                format!(
+                    "no cached response at {} and --offline was set",
+                    cache_path.display()
+                )
+            });
+        }
+
+        debug!("fetching {url}");
+        let text = self
+            .client
+            .get(url)
+            .headers(headers)
+            .send()
+            .await
+            .change_context_lazy(|| AppError::Fetch(url.to_string()))?
+            .text()
+            .await
+            .change_context_lazy(|| AppError::Fetch(url.to_string()))?;
+
+        if let Some(parent) = cache_path.parent() {
+            std::fs::create_dir_all(parent)
+                .change_context_lazy(|| AppError::Fetch(url.to_string()))?;
+        }
+        std::fs::write(&cache_path, &text)
+            .change_context_lazy(|| AppError::Fetch(url.to_string()))?;
+
+        Ok(text)
+    }
+
+    /// Like [`FetchClient::get_text`], but for binary assets (e.g. custom
+    /// emoji PNGs) that shouldn't be read back as UTF-8.
+    pub async fn get_bytes(&self, url: &str) -> AppResult<Vec<u8>> {
+        let cache_path = self.cache_dir.join(format!("{}.bin", cache_key(url)));
+
+        if cache_path.exists() {
+            let cached = std::fs::read(&cache_path)
+                .change_context_lazy(|| AppError::Fetch(url.to_string()))?;
+            debug!(
+                "replaying cached response for {url} from {}",
+                cache_path.display()
+            );
+            return Ok(cached);
+        }
+
+        if self.offline {
+            return Err(AppError::Fetch(url.to_string())).attach_printable_lazy(|| {
+                format!(
+                    "no cached response at {} and --offline was set",
+                    cache_path.display()
+                )
+            });
+        }
+
+        debug!("fetching {url}");
+        let bytes = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .change_context_lazy(|| AppError::Fetch(url.to_string()))?
+            .bytes()
+            .await
+            .change_context_lazy(|| AppError::Fetch(url.to_string()))?;
+
+        if let Some(parent) = cache_path.parent() {
+            std::fs::create_dir_all(parent)
+                .change_context_lazy(|| AppError::Fetch(url.to_string()))?;
+        }
+        std::fs::write(&cache_path, &bytes)
+            .change_context_lazy(|| AppError::Fetch(url.to_string()))?;
+
+        Ok(bytes.to_vec())
+    }
+}
+
+/// Turns a URL into a filesystem-safe cache file name.
+fn cache_key(url: &str) -> String {
+    url.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>()
+        + ".cache"
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use error_stack::ResultExt;
+use log::warn;
+use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT};
+use serde_json::Value;
+
+use crate::error::{AppError, AppResult};
+use crate::fetch::FetchClient;
+
+/// How the GitHub emoji API represents a single shortcode's image: either a
+/// Unicode glyph (rendered from its code points) or a GitHub-specific custom
+/// emoji (only ever available as a PNG asset).
+#[derive(Debug)]
+pub enum EmojiLiteral {
+    Unicode(Vec<char>),
+    Custom { asset_name: String, url: String },
+}
+
+pub async fn get_github_emoji_id_map(
+    fetch_client: &FetchClient,
+) -> AppResult<HashMap<String, EmojiLiteral>> {
+    let url = "https://api.github.com/emojis";
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        USER_AGENT,
+        HeaderValue::from_str("https://github.com/ikatyang/emoji-cheat-sheet")
+            .change_context_lazy(|| AppError::Fetch(url.to_string()))?,
+    );
+
+    let body = fetch_client.get_text(url, headers).await?;
+    let json: Value =
+        serde_json::from_str(&body).change_context_lazy(|| AppError::Json(url.to_string()))?;
+
+    let mut github_emoji_id_map = HashMap::new();
+    for (id, url) in json
+        .as_object()
+        .ok_or_else(|| AppError::Json(url.to_string()))?
+        .iter()
+    {
+        let emoji_literal = if url.as_str().unwrap().contains("/unicode/") {
+            let code_points: Vec<_> = url
+                .as_str()
+                .unwrap()
+                .split('/')
+                .next_back()
+                .unwrap()
+                .split(".png")
+                .next()
+                .unwrap()
+                .split('-')
+                .filter_map(|code_point_text| {
+                    u32::from_str_radix(code_point_text, 16)
+                        .ok()
+                        .and_then(std::char::from_u32)
+                })
+                .collect::<Vec<_>>();
+            if code_points.is_empty() {
+                warn!(
+                    "{}",
+                    error_stack::Report::new(AppError::InvalidCodePoint(id.to_string()))
+                );
+                continue;
+            }
+            EmojiLiteral::Unicode(code_points)
+        } else {
+            let download_url = url.as_str().unwrap().to_string();
+            let asset_name = download_url
+                .split('/')
+                .next_back()
+                .unwrap()
+                .split(".png")
+                .next()
+                .unwrap()
+                .to_string();
+            EmojiLiteral::Custom {
+                asset_name,
+                url: download_url,
+            }
+        };
+        github_emoji_id_map.insert(id.to_string(), emoji_literal);
+    }
+    Ok(github_emoji_id_map)
+}
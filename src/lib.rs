@@ -0,0 +1,224 @@
+pub mod categorize;
+pub mod error;
+pub mod fetch;
+pub mod github;
+pub mod locales;
+pub mod pack;
+pub mod render;
+pub mod unicode_list;
+
+use std::collections::HashMap;
+
+use categorize::Categorized;
+use error::AppResult;
+use fetch::FetchClient;
+
+/// A single emoji entry: its primary GitHub shortcode, any other shortcodes
+/// that render the same glyph, its Unicode literal (when it has one), and
+/// where it falls in the Unicode category/subcategory tree.
+#[derive(Debug, Clone)]
+pub struct Emoji {
+    pub shortcode: String,
+    pub aliases: Vec<String>,
+    pub unicode: Option<String>,
+    pub category: String,
+    pub subcategory: String,
+    /// Download URL for the GitHub custom emoji PNG backing this entry,
+    /// `None` for emoji that render from a Unicode literal instead.
+    pub asset_url: Option<String>,
+}
+
+/// The categorized emoji tree (as used by the cheat sheet renderers) plus a
+/// flat index for shortcode/Unicode lookup and fuzzy search.
+pub struct EmojiIndex {
+    pub categorized: Categorized,
+    emojis: Vec<Emoji>,
+    /// Shortcode, alias, or Unicode literal → position in `emojis`, so
+    /// `lookup` is a single map hit instead of a linear scan.
+    lookup_index: HashMap<String, usize>,
+}
+
+impl EmojiIndex {
+    /// Runs the GitHub + Unicode fetch pipeline and builds the index from
+    /// the result.
+    pub async fn build(fetch_client: &FetchClient) -> AppResult<Self> {
+        let github_emoji_id_map = github::get_github_emoji_id_map(fetch_client).await?;
+        let (categorized, emojis) =
+            categorize::categorize_github_emoji_ids(fetch_client, github_emoji_id_map).await?;
+
+        let mut lookup_index = HashMap::new();
+        for (i, emoji) in emojis.iter().enumerate() {
+            lookup_index.entry(emoji.shortcode.clone()).or_insert(i);
+            for alias in &emoji.aliases {
+                lookup_index.entry(alias.clone()).or_insert(i);
+            }
+            if let Some(unicode) = &emoji.unicode {
+                lookup_index.entry(unicode.clone()).or_insert(i);
+            }
+        }
+
+        Ok(Self {
+            categorized,
+            emojis,
+            lookup_index,
+        })
+    }
+
+    /// All emoji entries in the index, in the order they were discovered.
+    pub fn emojis(&self) -> &[Emoji] {
+        &self.emojis
+    }
+
+    /// Resolves a GitHub shortcode (`raised_eyebrow`) or a literal Unicode
+    /// string (`🤨`) to its emoji entry.
+    pub fn lookup(&self, query: &str) -> Option<&Emoji> {
+        self.lookup_index.get(query).map(|&i| &self.emojis[i])
+    }
+
+    /// Builds a shortcode → localized name map for every emoji that has
+    /// both a Unicode literal and a curated name in `locale` (see
+    /// [`locales`]). Shortcodes without an entry are left out, so callers
+    /// should fall back to the English shortcode themselves.
+    pub fn localized_names(&self, locale: &str) -> HashMap<String, String> {
+        self.emojis
+            .iter()
+            .filter_map(|emoji| {
+                let unicode = emoji.unicode.as_deref()?;
+                let name = locales::localized_name(locale, unicode)?;
+                Some((emoji.shortcode.clone(), name.to_string()))
+            })
+            .collect()
+    }
+
+    /// Fuzzy-matches `query` against every shortcode/alias, returning hits
+    /// ranked best match first so `"eyebrow"` surfaces `raised_eyebrow`.
+    pub fn search(&self, query: &str) -> Vec<&Emoji> {
+        let mut scored: Vec<(&Emoji, usize)> = self
+            .emojis
+            .iter()
+            .filter_map(|emoji| {
+                let best_score = std::iter::once(emoji.shortcode.as_str())
+                    .chain(emoji.aliases.iter().map(String::as_str))
+                    .filter_map(|candidate| fuzzy_score(query, candidate))
+                    .min()?;
+                Some((emoji, best_score))
+            })
+            .collect();
+        scored.sort_by_key(|(_, score)| *score);
+        scored.into_iter().map(|(emoji, _)| emoji).collect()
+    }
+}
+
+/// `None` unless `query` is a subsequence of `candidate`; otherwise the
+/// Levenshtein distance between them, so closer matches rank first.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<usize> {
+    let query = query.to_lowercase();
+    let candidate_lower = candidate.to_lowercase();
+    if !is_subsequence(&query, &candidate_lower) {
+        return None;
+    }
+    Some(levenshtein(&query, &candidate_lower))
+}
+
+fn is_subsequence(query: &str, candidate: &str) -> bool {
+    let mut candidate_chars = candidate.chars();
+    query.chars().all(|q| candidate_chars.any(|c| c == q))
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for i in 1..=a.len() {
+        let mut prev_diagonal = row[0];
+        row[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            let prev_above = row[j];
+            row[j] = (row[j] + 1).min(row[j - 1] + 1).min(prev_diagonal + cost);
+            prev_diagonal = prev_above;
+        }
+    }
+
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_index() -> EmojiIndex {
+        let emojis = vec![
+            Emoji {
+                shortcode: "raised_eyebrow".to_string(),
+                aliases: vec![],
+                unicode: Some("🤨".to_string()),
+                category: "Smileys & Emotion".to_string(),
+                subcategory: "face-unwell".to_string(),
+                asset_url: None,
+            },
+            Emoji {
+                shortcode: "eyes".to_string(),
+                aliases: vec!["look".to_string()],
+                unicode: Some("👀".to_string()),
+                category: "Smileys & Emotion".to_string(),
+                subcategory: "face-unwell".to_string(),
+                asset_url: None,
+            },
+        ];
+        let mut lookup_index = HashMap::new();
+        for (i, emoji) in emojis.iter().enumerate() {
+            lookup_index.entry(emoji.shortcode.clone()).or_insert(i);
+            for alias in &emoji.aliases {
+                lookup_index.entry(alias.clone()).or_insert(i);
+            }
+            if let Some(unicode) = &emoji.unicode {
+                lookup_index.entry(unicode.clone()).or_insert(i);
+            }
+        }
+        EmojiIndex {
+            categorized: Categorized::new(),
+            emojis,
+            lookup_index,
+        }
+    }
+
+    #[test]
+    fn lookup_resolves_shortcode_alias_and_unicode_literal() {
+        let index = sample_index();
+        assert_eq!(
+            index.lookup("raised_eyebrow").unwrap().shortcode,
+            "raised_eyebrow"
+        );
+        assert_eq!(index.lookup("🤨").unwrap().shortcode, "raised_eyebrow");
+        assert_eq!(index.lookup("look").unwrap().shortcode, "eyes");
+        assert!(index.lookup("nonexistent").is_none());
+    }
+
+    #[test]
+    fn search_ranks_closer_matches_first() {
+        let index = sample_index();
+        let results = index.search("eyebrow");
+        assert_eq!(results[0].shortcode, "raised_eyebrow");
+
+        let results = index.search("eys");
+        assert_eq!(results[0].shortcode, "eyes");
+    }
+
+    #[test]
+    fn fuzzy_score_requires_a_subsequence_and_ranks_by_edit_distance() {
+        assert_eq!(
+            fuzzy_score("eyebrow", "raised_eyebrow"),
+            Some(levenshtein("eyebrow", "raised_eyebrow"))
+        );
+        assert_eq!(fuzzy_score("xyz", "raised_eyebrow"), None);
+    }
+
+    #[test]
+    fn levenshtein_counts_single_character_edits() {
+        assert_eq!(levenshtein("eyes", "eyes"), 0);
+        assert_eq!(levenshtein("eys", "eyes"), 1);
+        assert_eq!(levenshtein("cat", "dog"), 3);
+    }
+}
@@ -0,0 +1,16 @@
+/// Hand-picked German names for the handful of emoji people actually type in
+/// issue comments and PR reviews — not the full CLDR annotation set, just
+/// enough to make `--locale de` useful today. Anything not listed here falls
+/// back to the English shortcode; add rows as they come up.
+pub static NAMES: &[(&str, &str)] = &[
+    ("😀", "grinsendes Gesicht"),
+    ("😂", "Gesicht mit Freudentränen"),
+    ("😍", "verliebtes Gesicht"),
+    ("🤔", "nachdenkendes Gesicht"),
+    ("👍", "Daumen hoch"),
+    ("👎", "Daumen runter"),
+    ("❤", "rotes Herz"),
+    ("🔥", "Feuer"),
+    ("🎉", "Partypopper"),
+    ("🙏", "faltende Hände"),
+];
@@ -0,0 +1,15 @@
+/// A small curated list of Spanish emoji names, not a CLDR import — ten
+/// common reactions translated by hand so `--locale es` has something
+/// to show. Unlisted emoji fall back to the English shortcode.
+pub static NAMES: &[(&str, &str)] = &[
+    ("😀", "cara sonriendo"),
+    ("😂", "cara llorando de risa"),
+    ("😍", "cara con ojos de corazón"),
+    ("🤔", "cara pensativa"),
+    ("👍", "pulgar hacia arriba"),
+    ("👎", "pulgar hacia abajo"),
+    ("❤", "corazón rojo"),
+    ("🔥", "fuego"),
+    ("🎉", "cotillón"),
+    ("🙏", "manos en oración"),
+];
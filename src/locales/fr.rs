@@ -0,0 +1,15 @@
+/// French names for the most common reaction emoji, picked by hand rather
+/// than pulled from CLDR. Coverage is intentionally small; anything missing
+/// falls back to the English shortcode until someone adds it.
+pub static NAMES: &[(&str, &str)] = &[
+    ("😀", "visage rieur"),
+    ("😂", "visage riant aux larmes"),
+    ("😍", "visage avec des yeux en cœur"),
+    ("🤔", "visage qui réfléchit"),
+    ("👍", "pouce levé"),
+    ("👎", "pouce baissé"),
+    ("❤", "cœur rouge"),
+    ("🔥", "feu"),
+    ("🎉", "ballon de fête"),
+    ("🙏", "mains en prière"),
+];
@@ -0,0 +1,16 @@
+/// Japanese names for a short list of everyday reaction emoji. This is not
+/// CLDR data (there's no dependency that vendors or generates it here) — just
+/// enough manual translations to make `--locale ja` worth passing. Everything
+/// else falls back to the English shortcode.
+pub static NAMES: &[(&str, &str)] = &[
+    ("😀", "にっこり笑う顔"),
+    ("😂", "うれし泣き"),
+    ("😍", "目がハートの顔"),
+    ("🤔", "考える顔"),
+    ("👍", "サムズアップ"),
+    ("👎", "サムズダウン"),
+    ("❤", "赤いハート"),
+    ("🔥", "炎"),
+    ("🎉", "パーティーポッパー"),
+    ("🙏", "祈る"),
+];
@@ -0,0 +1,51 @@
+#[cfg(feature = "locale-de")]
+mod de;
+#[cfg(feature = "locale-es")]
+mod es;
+#[cfg(feature = "locale-fr")]
+mod fr;
+#[cfg(feature = "locale-ja")]
+mod ja;
+
+/// Looks up the annotation name for a Unicode emoji literal (the same
+/// `String` key built in [`crate::categorize::categorize_github_emoji_ids`])
+/// in a small, hand-curated glossary for `locale`. This is not CLDR data and
+/// is nowhere near full localization — each locale only covers a short list
+/// of common reaction emoji, gated behind its own `locale-<code>` Cargo
+/// feature (see `Cargo.toml`). Returns `None` when `locale`'s feature isn't
+/// enabled or its glossary has no entry for this emoji yet, so callers can
+/// fall back to the English shortcode.
+#[cfg_attr(
+    not(any(
+        feature = "locale-de",
+        feature = "locale-es",
+        feature = "locale-fr",
+        feature = "locale-ja"
+    )),
+    allow(unused_variables, unreachable_code, clippy::match_single_binding)
+)]
+pub fn localized_name(locale: &str, unicode: &str) -> Option<&'static str> {
+    let table: &[(&str, &str)] = match locale {
+        #[cfg(feature = "locale-de")]
+        "de" => de::NAMES,
+        #[cfg(feature = "locale-es")]
+        "es" => es::NAMES,
+        #[cfg(feature = "locale-fr")]
+        "fr" => fr::NAMES,
+        #[cfg(feature = "locale-ja")]
+        "ja" => ja::NAMES,
+        _ => return None,
+    };
+    let unicode = strip_variation_selector(unicode);
+    table
+        .iter()
+        .find(|(literal, _)| strip_variation_selector(literal) == unicode)
+        .map(|(_, name)| *name)
+}
+
+/// Strips U+FE0F (`VARIATION SELECTOR-16`), so a literal built with the
+/// emoji-presentation selector (as `full-emoji-list.txt` uses, e.g. "❤️" =
+/// U+2764 U+FE0F) still matches a table key without it (e.g. "❤" = U+2764).
+fn strip_variation_selector(s: &str) -> String {
+    s.chars().filter(|&c| c != '\u{fe0f}').collect()
+}
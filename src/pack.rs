@@ -0,0 +1,136 @@
+use std::io::Write as _;
+use std::path::Path;
+
+use error_stack::ResultExt;
+use serde::Serialize;
+
+use crate::error::{AppError, AppResult};
+use crate::fetch::FetchClient;
+use crate::EmojiIndex;
+
+#[derive(Serialize)]
+struct PackEntry {
+    name: String,
+    category: String,
+    aliases: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct PackMeta {
+    emojis: Vec<PackEntry>,
+}
+
+/// Packages the fetched emoji set into a Misskey/Firefish-importable custom
+/// emoji pack: a `meta.json` manifest plus one PNG per emoji (the GitHub
+/// custom asset, or a Twemoji rendering for Unicode emoji) laid out under
+/// `custom/<category>/`, zipped up at `output_path`.
+///
+/// `staging_dir` is used to assemble the pack contents on disk before
+/// zipping and is left behind afterwards (callers may remove it).
+pub async fn export_pack(
+    fetch_client: &FetchClient,
+    index: &EmojiIndex,
+    staging_dir: &Path,
+    output_path: &Path,
+) -> AppResult<()> {
+    std::fs::create_dir_all(staging_dir)
+        .change_context_lazy(|| AppError::Output(staging_dir.display().to_string()))?;
+
+    let mut entries = Vec::new();
+    for emoji in index.emojis() {
+        entries.push(PackEntry {
+            name: emoji.shortcode.clone(),
+            category: emoji.category.clone(),
+            aliases: emoji.aliases.clone(),
+        });
+
+        let asset_url = emoji
+            .asset_url
+            .clone()
+            .or_else(|| emoji.unicode.as_deref().map(twemoji_url));
+        if let Some(url) = asset_url {
+            let category_dir = staging_dir.join("custom").join(sanitize(&emoji.category));
+            std::fs::create_dir_all(&category_dir)
+                .change_context_lazy(|| AppError::Output(category_dir.display().to_string()))?;
+            let bytes = fetch_client.get_bytes(&url).await?;
+            let asset_path = category_dir.join(format!("{}.png", emoji.shortcode));
+            std::fs::write(&asset_path, &bytes)
+                .change_context_lazy(|| AppError::Output(asset_path.display().to_string()))?;
+        }
+    }
+
+    let meta_path = staging_dir.join("meta.json");
+    let meta_json = serde_json::to_string_pretty(&PackMeta { emojis: entries })
+        .change_context_lazy(|| AppError::Output(meta_path.display().to_string()))?;
+    std::fs::write(&meta_path, meta_json)
+        .change_context_lazy(|| AppError::Output(meta_path.display().to_string()))?;
+
+    zip_directory(staging_dir, output_path)
+}
+
+/// Twemoji CDN URL for a Unicode glyph, so pack entries without a GitHub
+/// custom asset still get a renderable image instead of metadata only.
+/// Twemoji's PNGs are named by lowercase hex code points joined with `-`,
+/// with the `fe0f` variation selector stripped.
+fn twemoji_url(unicode: &str) -> String {
+    let code_points = unicode
+        .chars()
+        .filter(|&c| c != '\u{fe0f}')
+        .map(|c| format!("{:x}", c as u32))
+        .collect::<Vec<_>>()
+        .join("-");
+    format!("https://cdn.jsdelivr.net/gh/twitter/twemoji@latest/assets/72x72/{code_points}.png")
+}
+
+/// Replaces anything that isn't filesystem-friendly with `_` (category names
+/// can contain spaces, e.g. "Smileys & Emotion").
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn zip_directory(dir: &Path, output_path: &Path) -> AppResult<()> {
+    let file = std::fs::File::create(output_path)
+        .change_context_lazy(|| AppError::Output(output_path.display().to_string()))?;
+    let mut writer = zip::ZipWriter::new(file);
+    let options =
+        zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    let mut stack = vec![dir.to_path_buf()];
+    while let Some(current) = stack.pop() {
+        for child in std::fs::read_dir(&current)
+            .change_context_lazy(|| AppError::Output(current.display().to_string()))?
+        {
+            let child =
+                child.change_context_lazy(|| AppError::Output(current.display().to_string()))?;
+            let path = child.path();
+            let name = path
+                .strip_prefix(dir)
+                .unwrap()
+                .to_string_lossy()
+                .into_owned();
+
+            if path.is_dir() {
+                writer
+                    .add_directory(format!("{name}/"), options)
+                    .change_context_lazy(|| AppError::Output(output_path.display().to_string()))?;
+                stack.push(path);
+            } else {
+                writer
+                    .start_file(name, options)
+                    .change_context_lazy(|| AppError::Output(output_path.display().to_string()))?;
+                let bytes = std::fs::read(&path)
+                    .change_context_lazy(|| AppError::Output(path.display().to_string()))?;
+                writer
+                    .write_all(&bytes)
+                    .change_context_lazy(|| AppError::Output(output_path.display().to_string()))?;
+            }
+        }
+    }
+
+    writer
+        .finish()
+        .change_context_lazy(|| AppError::Output(output_path.display().to_string()))?;
+    Ok(())
+}
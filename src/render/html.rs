@@ -0,0 +1,67 @@
+use super::Renderer;
+use crate::categorize::Categorized;
+use crate::error::AppResult;
+
+/// Renders a self-contained, searchable HTML page: one collapsible
+/// `<details>` section per category with a text filter over shortcodes.
+pub struct HtmlRenderer {
+    pub repo_name: String,
+}
+
+impl Renderer for HtmlRenderer {
+    fn render(&self, data: &Categorized) -> AppResult<String> {
+        let mut sections = String::new();
+        for (category, subcategories) in data {
+            sections.push_str(&format!(
+                "<details class=\"category\" open>\n<summary>{category}</summary>\n"
+            ));
+            for (subcategory, groups) in subcategories {
+                if !subcategory.is_empty() {
+                    sections.push_str(&format!("<h4>{subcategory}</h4>\n"));
+                }
+                sections.push_str("<ul class=\"emoji-list\">\n");
+                for group in groups {
+                    if let Some(shortcode) = group.first() {
+                        sections.push_str(&format!(
+                            "<li class=\"emoji\" data-name=\"{shortcode}\"><code>:{shortcode}:</code></li>\n"
+                        ));
+                    }
+                }
+                sections.push_str("</ul>\n");
+            }
+            sections.push_str("</details>\n");
+        }
+
+        Ok(format!(
+            r#"<!doctype html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{repo_name}</title>
+<style>
+body {{ font-family: sans-serif; margin: 2rem; }}
+.emoji-list {{ list-style: none; padding: 0; display: flex; flex-wrap: wrap; gap: 0.5rem; }}
+.emoji {{ border: 1px solid #ddd; border-radius: 4px; padding: 0.25rem 0.5rem; }}
+.emoji[hidden] {{ display: none; }}
+</style>
+</head>
+<body>
+<h1>{repo_name}</h1>
+<input id="filter" type="search" placeholder="Filter shortcodes…" autofocus>
+{sections}
+<script>
+document.getElementById('filter').addEventListener('input', (event) => {{
+  const query = event.target.value.toLowerCase();
+  document.querySelectorAll('.emoji').forEach((el) => {{
+    el.hidden = query !== '' && !el.dataset.name.toLowerCase().includes(query);
+  }});
+}});
+</script>
+</body>
+</html>
+"#,
+            repo_name = self.repo_name,
+            sections = sections,
+        ))
+    }
+}
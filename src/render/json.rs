@@ -0,0 +1,15 @@
+use error_stack::ResultExt;
+
+use super::Renderer;
+use crate::categorize::Categorized;
+use crate::error::{AppError, AppResult};
+
+/// Serializes the category → subcategory → emoji tree as JSON, for
+/// downstream tooling rather than human reading.
+pub struct JsonRenderer;
+
+impl Renderer for JsonRenderer {
+    fn render(&self, data: &Categorized) -> AppResult<String> {
+        serde_json::to_string_pretty(data).change_context(AppError::Render("JSON".to_string()))
+    }
+}
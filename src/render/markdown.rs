@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+use super::Renderer;
+use crate::categorize::Categorized;
+use crate::error::AppResult;
+
+/// Renders the categorized emoji tree as a GitHub-flavored markdown cheat
+/// sheet: a table of contents followed by one emoji table per
+/// category/subcategory.
+pub struct MarkdownRenderer {
+    pub repo_name: String,
+    pub resource1: String,
+    pub resource2: String,
+    pub columns: usize,
+    pub toc_name: String,
+    /// Shortcode → localized name, from `EmojiIndex::localized_names`. When
+    /// set, each emoji row gets an extra column with its localized name
+    /// (falling back to the English shortcode where the locale has no
+    /// annotation).
+    pub locale_names: Option<HashMap<String, String>>,
+}
+
+impl Renderer for MarkdownRenderer {
+    fn render(&self, data: &Categorized) -> AppResult<String> {
+        Ok(generate_cheat_sheet(
+            &self.repo_name,
+            &self.resource1,
+            &self.resource2,
+            self.columns,
+            &self.toc_name,
+            self.locale_names.as_ref(),
+            data,
+        ))
+    }
+}
+
+fn generate_cheat_sheet(
+    repo_name: &str,
+    resource1: &str,
+    resource2: &str,
+    columns: usize,
+    toc_name: &str,
+    locale_names: Option<&HashMap<String, String>>,
+    categorized_github_emoji_ids: &Categorized,
+) -> String {
+    let mut line_texts = Vec::new();
+
+    line_texts.push(format!("# {}", repo_name));
+    line_texts.push("".to_string());
+    line_texts.push("".to_string());
+    line_texts.push(format!(
+        "This cheat sheet is automatically generated from [{}]({}) and [{}]({}).",
+        resource1,
+        "https://api.github.com/emojis",
+        resource2,
+        "https://unicode.org/emoji/charts/full-emoji-list.txt"
+    ));
+    line_texts.push("".to_string());
+
+    let categories: Vec<&String> = categorized_github_emoji_ids.keys().collect();
+
+    line_texts.push(format!("## {}", toc_name));
+    line_texts.push("".to_string());
+    line_texts.extend(generate_toc(&categories));
+    line_texts.push("".to_string());
+
+    for category in &categories {
+        line_texts.push(format!("### {}", category));
+        line_texts.push("".to_string());
+
+        let subcategorize_github_emoji_ids = &categorized_github_emoji_ids[*category];
+        let subcategories: Vec<&String> = subcategorize_github_emoji_ids.keys().collect();
+        if subcategories.len() > 1 {
+            line_texts.extend(generate_toc(&subcategories));
+            line_texts.push("".to_string());
+        }
+
+        for subcategory in &subcategories {
+            if !subcategory.is_empty() {
+                line_texts.push(format!("#### {}", subcategory));
+                line_texts.push("".to_string());
+            }
+
+            line_texts.extend(generate_table(
+                &subcategorize_github_emoji_ids[*subcategory],
+                columns,
+                locale_names,
+                &format!("[top](#{})", get_header_id(category)),
+                &format!("[top](#{})", get_header_id(toc_name)),
+            ));
+            line_texts.push("".to_string());
+        }
+    }
+
+    line_texts.join("\n")
+}
+
+fn generate_toc(headers: &[&String]) -> Vec<String> {
+    headers
+        .iter()
+        .map(|header| format!("- [{}](#{})", header, get_header_id(header)))
+        .collect()
+}
+
+fn get_header_id(header: &str) -> String {
+    header
+        .to_lowercase()
+        .replace(" ", "-")
+        .replace(|c: char| !c.is_ascii_alphanumeric() && c != '-', "")
+}
+
+fn generate_table(
+    github_emoji_ids: &[Vec<String>],
+    columns: usize,
+    locale_names: Option<&HashMap<String, String>>,
+    left_text: &str,
+    right_text: &str,
+) -> Vec<String> {
+    let mut line_texts = Vec::new();
+
+    let mut header = "| ".to_string();
+    let mut delimiter = "| - ".to_string();
+    for _ in 0..columns.min(github_emoji_ids.len()) {
+        header += "| ico | shortcode ";
+        delimiter += "| :-: | - ";
+        if locale_names.is_some() {
+            header += "| name ";
+            delimiter += "| - ";
+        }
+    }
+    header += "| |";
+    delimiter += "| - |";
+
+    line_texts.push(header);
+    line_texts.push(delimiter);
+
+    for i in (0..github_emoji_ids.len()).step_by(columns) {
+        let mut line_text = format!("| {} ", left_text);
+        for j in 0..columns {
+            if i + j < github_emoji_ids.len() {
+                let emoji_ids = &github_emoji_ids[i + j];
+                let emoji_id = &emoji_ids[0];
+                line_text += &format!("| :{}: | `:{}:` ", emoji_id, emoji_id);
+                for alias in &emoji_ids[1..] {
+                    line_text += &format!("<br /> `:{}:` ", alias);
+                }
+                if let Some(locale_names) = locale_names {
+                    let name = locale_names
+                        .get(emoji_id)
+                        .map(String::as_str)
+                        .unwrap_or(emoji_id);
+                    line_text += &format!("| {} ", name);
+                }
+            } else if github_emoji_ids.len() > columns {
+                line_text += "| | ";
+                if locale_names.is_some() {
+                    line_text += "| ";
+                }
+            }
+        }
+        line_text += &format!("| {} |", right_text);
+        line_texts.push(line_text);
+    }
+
+    line_texts
+}
@@ -0,0 +1,17 @@
+mod html;
+mod json;
+mod markdown;
+
+pub use html::HtmlRenderer;
+pub use json::JsonRenderer;
+pub use markdown::MarkdownRenderer;
+
+use crate::categorize::Categorized;
+use crate::error::AppResult;
+
+/// Turns the category → subcategory → emoji tree into a specific output
+/// format. The tree itself is already format-agnostic, so implementations
+/// only need to decide how to stringify it.
+pub trait Renderer {
+    fn render(&self, data: &Categorized) -> AppResult<String>;
+}
@@ -0,0 +1,110 @@
+/// One logical entry from `full-emoji-list.txt`: a top-level `@@` category
+/// header, a `@` subcategory header, or an emoji data line.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FullEmojiListEntry {
+    Category(String),
+    Subcategory(String),
+    Emoji(String),
+}
+
+/// Streams [`FullEmojiListEntry`] items out of the Unicode plain-text emoji
+/// list, line by line, so callers never have to build the whole parsed table
+/// (or an HTML DOM of it) at once. The source text itself is borrowed whole.
+pub struct FullEmojiList<'a> {
+    lines: std::str::Lines<'a>,
+}
+
+impl<'a> FullEmojiList<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            lines: text.lines(),
+        }
+    }
+}
+
+impl<'a> Iterator for FullEmojiList<'a> {
+    type Item = FullEmojiListEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = self.lines.next()?;
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(title) = line.strip_prefix("@@") {
+                return Some(FullEmojiListEntry::Category(title.trim().to_string()));
+            }
+            if let Some(title) = line.strip_prefix('@') {
+                return Some(FullEmojiListEntry::Subcategory(title.trim().to_string()));
+            }
+
+            let code_points_field = match line.split('\t').next() {
+                Some(field) => field,
+                None => continue,
+            };
+            let emoji: String = code_points_field
+                .split_whitespace()
+                .filter_map(|code_point_text| {
+                    u32::from_str_radix(code_point_text, 16)
+                        .ok()
+                        .and_then(std::char::from_u32)
+                })
+                .collect();
+            if emoji.is_empty() {
+                continue;
+            }
+            return Some(FullEmojiListEntry::Emoji(emoji));
+        }
+    }
+}
+
+/// Title-cases a Unicode category/subcategory name like `face-smiling`.
+pub fn to_title_case(s: String) -> String {
+    s.replace("-", " ")
+        .split_whitespace()
+        .map(|word| {
+            let mut c = word.chars();
+            match c.next() {
+                None => String::new(),
+                Some(f) => f.to_uppercase().chain(c).collect(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn yields_categories_subcategories_and_emoji_in_order() {
+        let text = "\
+# comments and blank lines are ignored
+
+@@ Smileys & Emotion
+@ face-smiling
+1F600\tfully-qualified\t😀 grinning face
+1F602\tfully-qualified\t😂 face with tears of joy
+@ face-affection
+1F970\tfully-qualified\t🥰 smiling face with hearts
+";
+        let entries: Vec<_> = FullEmojiList::new(text).collect();
+        assert_eq!(
+            entries,
+            vec![
+                FullEmojiListEntry::Category("Smileys & Emotion".to_string()),
+                FullEmojiListEntry::Subcategory("face-smiling".to_string()),
+                FullEmojiListEntry::Emoji("😀".to_string()),
+                FullEmojiListEntry::Emoji("😂".to_string()),
+                FullEmojiListEntry::Subcategory("face-affection".to_string()),
+                FullEmojiListEntry::Emoji("🥰".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn to_title_case_converts_hyphenated_names() {
+        assert_eq!(to_title_case("face-smiling".to_string()), "Face Smiling");
+    }
+}